@@ -16,10 +16,72 @@
 //! Byte-swapping (like the std `to_le` functions) is only needed to convert
 //! to/from byte sequences, and since its purpose is reproducibility,
 //! non-reproducible sources (e.g. `OsRng`) need not bother with it.
+//!
+//! The `_endian` variants (e.g. `fill_via_u32_chunks_endian`) generalise
+//! this to an arbitrary [`Endianness`], for RNG wrappers that must
+//! reproduce byte streams generated by a big-endian reference
+//! implementation.
 
 use crate::RngCore;
 use core::cmp::min;
 
+mod private {
+    pub trait Sealed {}
+}
+
+/// Marker trait for byte order, used to select endianness in the `_endian`
+/// variants of the chunk-filling helpers below.
+///
+/// This trait is sealed and cannot be implemented outside of `rand_core`;
+/// [`LittleEndian`] and [`BigEndian`] are the only implementations.
+pub trait Endianness: private::Sealed {
+    #[doc(hidden)]
+    const IS_LITTLE: bool;
+    #[doc(hidden)]
+    fn to_bytes_u32(n: u32) -> [u8; 4];
+    #[doc(hidden)]
+    fn to_bytes_u64(n: u64) -> [u8; 8];
+    #[doc(hidden)]
+    fn to_bytes_u128(n: u128) -> [u8; 16];
+}
+
+/// Little-endian byte order: least-significant byte first.
+#[derive(Debug, Clone, Copy)]
+pub struct LittleEndian;
+
+/// Big-endian byte order: most-significant byte first.
+#[derive(Debug, Clone, Copy)]
+pub struct BigEndian;
+
+impl private::Sealed for LittleEndian {}
+impl private::Sealed for BigEndian {}
+
+impl Endianness for LittleEndian {
+    const IS_LITTLE: bool = true;
+    fn to_bytes_u32(n: u32) -> [u8; 4] {
+        n.to_le_bytes()
+    }
+    fn to_bytes_u64(n: u64) -> [u8; 8] {
+        n.to_le_bytes()
+    }
+    fn to_bytes_u128(n: u128) -> [u8; 16] {
+        n.to_le_bytes()
+    }
+}
+
+impl Endianness for BigEndian {
+    const IS_LITTLE: bool = false;
+    fn to_bytes_u32(n: u32) -> [u8; 4] {
+        n.to_be_bytes()
+    }
+    fn to_bytes_u64(n: u64) -> [u8; 8] {
+        n.to_be_bytes()
+    }
+    fn to_bytes_u128(n: u128) -> [u8; 16] {
+        n.to_be_bytes()
+    }
+}
+
 /// Implement `next_u64` via `next_u32`, little-endian order.
 pub fn next_u64_via_u32<R: RngCore + ?Sized>(rng: &mut R) -> u64 {
     // Use LE; we explicitly generate one value before the next.
@@ -52,8 +114,14 @@ pub fn fill_bytes_via_next<R: RngCore + ?Sized>(rng: &mut R, dest: &mut [u8]) {
     }
 }
 
-macro_rules! fill_via_chunks {
-    ($src:expr, $dst:expr, $ty:ty) => {{
+// The per-word fallback, used whenever `fill_via_chunks!` cannot bulk-copy:
+// for `BigEndian` output on any target, and for `LittleEndian` output on a
+// big-endian target. Pulled
+// out of `fill_via_chunks!` so it has a single definition that both the
+// runtime `cfg!` branch and tests (which need to exercise it regardless of
+// the host's actual endianness) go through.
+macro_rules! fill_via_chunks_slow {
+    ($src:expr, $dst:expr, $ty:ty, $E:ty, $conv:ident) => {{
         const SIZE: usize = core::mem::size_of::<$ty>();
         let chunk_size_u8 = min($src.len() * SIZE, $dst.len());
         let chunk_size = (chunk_size_u8 + SIZE - 1) / SIZE;
@@ -61,17 +129,58 @@ macro_rules! fill_via_chunks {
         let mut iter_src = $src.iter();
         let mut chunks = $dst.chunks_exact_mut(SIZE);
         for (chunk, n) in (&mut chunks).zip(&mut iter_src) {
-            chunk.copy_from_slice(&n.to_le_bytes());
+            chunk.copy_from_slice(&<$E as Endianness>::$conv(*n));
         }
         let rem = chunks.into_remainder();
         if let Some(n) = iter_src.next() {
-            rem.copy_from_slice(&n.to_le_bytes()[..rem.len()]);
+            rem.copy_from_slice(&<$E as Endianness>::$conv(*n)[..rem.len()]);
         }
 
         (chunk_size, chunk_size_u8)
     }};
 }
 
+macro_rules! fill_via_chunks {
+    ($src:expr, $dst:expr, u32) => {
+        fill_via_chunks!($src, $dst, u32, LittleEndian, to_bytes_u32)
+    };
+    ($src:expr, $dst:expr, u64) => {
+        fill_via_chunks!($src, $dst, u64, LittleEndian, to_bytes_u64)
+    };
+    ($src:expr, $dst:expr, u128) => {
+        fill_via_chunks!($src, $dst, u128, LittleEndian, to_bytes_u128)
+    };
+    ($src:expr, $dst:expr, $ty:ty, $E:ty, $conv:ident) => {{
+        const SIZE: usize = core::mem::size_of::<$ty>();
+        let chunk_size_u8 = min($src.len() * SIZE, $dst.len());
+        let chunk_size = (chunk_size_u8 + SIZE - 1) / SIZE;
+
+        if cfg!(target_endian = "little") && <$E as Endianness>::IS_LITTLE {
+            // On a little-endian target, producing little-endian output
+            // means the in-memory representation of `$src` already *is* the
+            // byte stream we want, so we can bulk-copy instead of
+            // converting one word at a time.
+            //
+            // SAFETY: `$src` is valid for reads of at least `chunk_size_u8`
+            // bytes (it has at least `chunk_size` elements of size `SIZE`,
+            // and `chunk_size_u8 <= chunk_size * SIZE`), and `$dst` is valid
+            // for writes of `chunk_size_u8` bytes since
+            // `chunk_size_u8 <= $dst.len()`. The two do not overlap since
+            // they come from distinct slices of different element types.
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    $src.as_ptr() as *const u8,
+                    $dst.as_mut_ptr(),
+                    chunk_size_u8,
+                );
+            }
+            (chunk_size, chunk_size_u8)
+        } else {
+            fill_via_chunks_slow!($src, $dst, $ty, $E, $conv)
+        }
+    }};
+}
+
 /// Implement `fill_bytes` by reading chunks from the output buffer of a block
 /// based RNG.
 ///
@@ -120,6 +229,92 @@ pub fn fill_via_u64_chunks(src: &[u64], dest: &mut [u8]) -> (usize, usize) {
     fill_via_chunks!(src, dest, u64)
 }
 
+/// Implement `fill_bytes` by reading chunks from the output buffer of a block
+/// based RNG.
+///
+/// The return values are `(consumed_u128, filled_u8)`.
+/// `filled_u8` is the number of filled bytes in `dest`, which may be less than
+/// the length of `dest`.
+/// `consumed_u128` is the number of words consumed from `src`, which is the
+/// same as `filled_u8 / 16` rounded up.
+///
+/// See `fill_via_u32_chunks` for an example.
+pub fn fill_via_u128_chunks(src: &[u128], dest: &mut [u8]) -> (usize, usize) {
+    fill_via_chunks!(src, dest, u128)
+}
+
+/// Implement `fill_bytes` by reading chunks from the output buffer of a block
+/// based RNG, with the byte order selected by `E`.
+///
+/// This is the endianness-generic counterpart to `fill_via_u32_chunks`,
+/// which is equivalent to `fill_via_u32_chunks_endian::<LittleEndian>`. It
+/// allows RNG wrappers that must reproduce a big-endian reference
+/// implementation to emit `BigEndian` output instead.
+///
+/// The return values are `(consumed_u32, filled_u8)`; see
+/// `fill_via_u32_chunks` for details.
+pub fn fill_via_u32_chunks_endian<E: Endianness>(src: &[u32], dest: &mut [u8]) -> (usize, usize) {
+    fill_via_chunks!(src, dest, u32, E, to_bytes_u32)
+}
+
+/// Implement `fill_bytes` by reading chunks from the output buffer of a block
+/// based RNG, with the byte order selected by `E`.
+///
+/// This is the endianness-generic counterpart to `fill_via_u64_chunks`,
+/// which is equivalent to `fill_via_u64_chunks_endian::<LittleEndian>`.
+///
+/// The return values are `(consumed_u64, filled_u8)`; see
+/// `fill_via_u64_chunks` for details.
+pub fn fill_via_u64_chunks_endian<E: Endianness>(src: &[u64], dest: &mut [u8]) -> (usize, usize) {
+    fill_via_chunks!(src, dest, u64, E, to_bytes_u64)
+}
+
+/// Reads unsigned 32 bit integers from `src` into `dst`, little-endian
+/// order.
+///
+/// The inverse of `fill_via_u32_chunks`: useful for `SeedableRng::from_seed`
+/// implementations which need to split a `[u8; N]` seed into `[u32; K]`
+/// state.
+///
+/// # Panics
+///
+/// In debug builds, panics if `src.len() != dst.len() * 4`. Supplying a
+/// short or otherwise mismatched `src` is a programmer error.
+pub fn read_u32_into(src: &[u8], dst: &mut [u32]) {
+    debug_assert!(
+        src.len() == core::mem::size_of_val(dst),
+        "src.len() must equal dst.len() * 4"
+    );
+    for (out, chunk) in dst.iter_mut().zip(src.chunks_exact(4)) {
+        let mut buf = [0u8; 4];
+        buf.copy_from_slice(chunk);
+        *out = u32::from_le_bytes(buf);
+    }
+}
+
+/// Reads unsigned 64 bit integers from `src` into `dst`, little-endian
+/// order.
+///
+/// The inverse of `fill_via_u64_chunks`: useful for `SeedableRng::from_seed`
+/// implementations which need to split a `[u8; N]` seed into `[u64; K]`
+/// state.
+///
+/// # Panics
+///
+/// In debug builds, panics if `src.len() != dst.len() * 8`. Supplying a
+/// short or otherwise mismatched `src` is a programmer error.
+pub fn read_u64_into(src: &[u8], dst: &mut [u64]) {
+    debug_assert!(
+        src.len() == core::mem::size_of_val(dst),
+        "src.len() must equal dst.len() * 8"
+    );
+    for (out, chunk) in dst.iter_mut().zip(src.chunks_exact(8)) {
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(chunk);
+        *out = u64::from_le_bytes(buf);
+    }
+}
+
 /// Implement `next_u32` via `fill_bytes`, little-endian order.
 pub fn next_u32_via_fill<R: RngCore + ?Sized>(rng: &mut R) -> u32 {
     let mut buf = [0; 4];
@@ -134,6 +329,16 @@ pub fn next_u64_via_fill<R: RngCore + ?Sized>(rng: &mut R) -> u64 {
     u64::from_ne_bytes(buf)
 }
 
+/// Implement a 128-bit output via `fill_bytes`, little-endian order.
+///
+/// This is a convenience for counter-based and SIMD-backed generators that
+/// keep their output buffer as `u128` lanes.
+pub fn next_u128_via_fill<R: RngCore + ?Sized>(rng: &mut R) -> u128 {
+    let mut buf = [0; 16];
+    rng.fill_bytes(&mut buf);
+    u128::from_le_bytes(buf)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -169,4 +374,108 @@ mod test {
         assert_eq!(fill_via_u64_chunks(&src, &mut dst), (1, 5));
         assert_eq!(dst, [1, 0, 0, 0, 0]);
     }
+
+    #[test]
+    fn test_fill_via_u128_chunks() {
+        let src = [1, 2];
+        let mut dst = [0u8; 20];
+        assert_eq!(fill_via_u128_chunks(&src, &mut dst), (2, 20));
+        assert_eq!(
+            dst,
+            [
+                1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0
+            ]
+        );
+
+        let mut dst = [0u8; 32];
+        assert_eq!(fill_via_u128_chunks(&src, &mut dst), (2, 32));
+        assert_eq!(
+            dst,
+            [
+                1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 0
+            ]
+        );
+
+        let mut dst = [0u8; 10];
+        assert_eq!(fill_via_u128_chunks(&src, &mut dst), (1, 10));
+        assert_eq!(dst, [1, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_fill_via_u32_chunks_endian() {
+        let src = [1u32, 2, 3];
+
+        let mut le = [0u8; 12];
+        assert_eq!(fill_via_u32_chunks_endian::<LittleEndian>(&src, &mut le), (3, 12));
+        assert_eq!(le, [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0]);
+
+        let mut be = [0u8; 12];
+        assert_eq!(fill_via_u32_chunks_endian::<BigEndian>(&src, &mut be), (3, 12));
+        assert_eq!(be, [0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 3]);
+    }
+
+    #[test]
+    fn test_fill_via_u64_chunks_endian() {
+        let src = [1u64, 2];
+
+        let mut le = [0u8; 16];
+        assert_eq!(fill_via_u64_chunks_endian::<LittleEndian>(&src, &mut le), (2, 16));
+        assert_eq!(le, [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+
+        let mut be = [0u8; 16];
+        assert_eq!(fill_via_u64_chunks_endian::<BigEndian>(&src, &mut be), (2, 16));
+        assert_eq!(be, [0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2]);
+    }
+
+    // `fill_via_chunks!`'s guard is `cfg!(target_endian = "little") &&
+    // E::IS_LITTLE`, so the `::<BigEndian>` calls in
+    // `test_fill_via_u32_chunks_endian`/`test_fill_via_u64_chunks_endian`
+    // already exercise `fill_via_chunks_slow!` on an ordinary little-endian
+    // host. What neither of those nor this test covers is the other half of
+    // the guard: a genuine big-endian compile target, where
+    // `fill_via_u32/64/128_chunks` (the plain `LittleEndian` aliases) would
+    // also take this branch. This test still can't exercise that leg either
+    // (it calls the macro directly, bypassing `cfg!` entirely) — it only
+    // checks that the slow path's output agrees with the fast path's for
+    // `LittleEndian`, independent of which one the current host picks.
+    #[test]
+    fn test_fill_via_chunks_slow_matches_fast_path() {
+        let src32 = [1u32, 2, 3];
+        let mut slow = [0u8; 11];
+        let slow_ret = fill_via_chunks_slow!(&src32, &mut slow, u32, LittleEndian, to_bytes_u32);
+        let mut fast = [0u8; 11];
+        assert_eq!(slow_ret, fill_via_u32_chunks(&src32, &mut fast));
+        assert_eq!(slow, fast);
+
+        let src64 = [1u64, 2];
+        let mut slow = [0u8; 11];
+        let slow_ret = fill_via_chunks_slow!(&src64, &mut slow, u64, LittleEndian, to_bytes_u64);
+        let mut fast = [0u8; 11];
+        assert_eq!(slow_ret, fill_via_u64_chunks(&src64, &mut fast));
+        assert_eq!(slow, fast);
+
+        let src128 = [1u128, 2];
+        let mut slow = [0u8; 20];
+        let slow_ret = fill_via_chunks_slow!(&src128, &mut slow, u128, LittleEndian, to_bytes_u128);
+        let mut fast = [0u8; 20];
+        assert_eq!(slow_ret, fill_via_u128_chunks(&src128, &mut fast));
+        assert_eq!(slow, fast);
+    }
+
+    #[test]
+    fn test_read_u32_into() {
+        let src = [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0];
+        let mut dst = [0u32; 3];
+        read_u32_into(&src, &mut dst);
+        assert_eq!(dst, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_read_u64_into() {
+        let src = [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0];
+        let mut dst = [0u64; 2];
+        read_u64_into(&src, &mut dst);
+        assert_eq!(dst, [1, 2]);
+    }
 }